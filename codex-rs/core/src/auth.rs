@@ -0,0 +1,400 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+const ACCOUNTS_FILE_NAME: &str = "accounts.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountKind {
+    ChatGpt,
+    ApiKey,
+}
+
+/// Read-only view of a stored account, as handed to UI code. Secrets never
+/// appear here directly: API keys are masked and ChatGPT credentials are
+/// represented only by the fact that the account exists.
+#[derive(Debug, Clone)]
+pub struct AccountSummary {
+    pub id: String,
+    pub kind: AccountKind,
+    pub label: String,
+    pub custom_label: Option<String>,
+    pub email: Option<String>,
+    pub masked_api_key: Option<String>,
+    pub is_active: bool,
+}
+
+#[derive(Debug)]
+pub struct AuthError(String);
+
+impl std::fmt::Display for AuthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for AuthError {}
+
+impl From<io::Error> for AuthError {
+    fn from(err: io::Error) -> Self {
+        Self(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for AuthError {
+    fn from(err: serde_json::Error) -> Self {
+        Self(err.to_string())
+    }
+}
+
+pub type Result<T> = std::result::Result<T, AuthError>;
+
+/// On-disk representation of one account, including whatever secret lets us
+/// re-authenticate as it. This is the record that gets serialized into
+/// `accounts.json`; `AccountSummary` is the sanitized projection of it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct AccountRecord {
+    id: String,
+    kind: AccountKind,
+    label: String,
+    custom_label: Option<String>,
+    email: Option<String>,
+    api_key: Option<String>,
+    chatgpt_credentials: Option<serde_json::Value>,
+}
+
+impl AccountRecord {
+    fn to_summary(&self, active_account_id: Option<&str>) -> AccountSummary {
+        AccountSummary {
+            id: self.id.clone(),
+            kind: self.kind,
+            label: self.label.clone(),
+            custom_label: self.custom_label.clone(),
+            email: self.email.clone(),
+            masked_api_key: self.api_key.as_deref().map(mask_api_key),
+            is_active: active_account_id == Some(self.id.as_str()),
+        }
+    }
+}
+
+fn mask_api_key(key: &str) -> String {
+    let tail: String = key
+        .chars()
+        .rev()
+        .take(4)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .collect();
+    format!("sk-...{tail}")
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct AccountsData {
+    active_account_id: Option<String>,
+    accounts: Vec<AccountRecord>,
+}
+
+/// Owns the on-disk store of every account the user has authenticated with
+/// (`accounts.json` under the codex home directory) and tracks which one is
+/// active for the current session.
+pub struct AuthManager {
+    accounts_file: PathBuf,
+    data: Mutex<AccountsData>,
+    switch_generation: AtomicU64,
+}
+
+impl AuthManager {
+    pub fn new(codex_home: impl AsRef<Path>) -> Result<Self> {
+        let accounts_file = codex_home.as_ref().join(ACCOUNTS_FILE_NAME);
+        let data = Self::load(&accounts_file)?;
+        Ok(Self {
+            accounts_file,
+            data: Mutex::new(data),
+            switch_generation: AtomicU64::new(0),
+        })
+    }
+
+    fn load(accounts_file: &Path) -> Result<AccountsData> {
+        match fs::read_to_string(accounts_file) {
+            Ok(contents) => Ok(serde_json::from_str(&contents)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(AccountsData::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn persist(&self, data: &AccountsData) -> Result<()> {
+        if let Some(parent) = self.accounts_file.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let serialized = serde_json::to_string_pretty(data)?;
+        fs::write(&self.accounts_file, serialized)?;
+        Ok(())
+    }
+
+    pub fn list_accounts(&self) -> Result<Vec<AccountSummary>> {
+        let data = self.data.lock().unwrap();
+        Ok(data
+            .accounts
+            .iter()
+            .map(|record| record.to_summary(data.active_account_id.as_deref()))
+            .collect())
+    }
+
+    pub fn select_account(&self, id: &str) -> Result<()> {
+        let mut data = self.data.lock().unwrap();
+        if !data.accounts.iter().any(|record| record.id == id) {
+            return Err(AuthError(format!("no such account: {id}")));
+        }
+        data.active_account_id = Some(id.to_string());
+        self.persist(&data)
+    }
+
+    /// Bumps and returns the current switch generation, invalidating any
+    /// token handed out by an earlier call. Callers that dispatch a
+    /// cancellable `select_account_if_current` onto a blocking thread pool
+    /// call this once to obtain their token, and call it again (discarding
+    /// the result) to invalidate an in-flight switch without starting a new
+    /// one. This exists because a blocking task already running when its
+    /// caller cancels can't actually be interrupted mid-flight -- the
+    /// generation check at persist time is what keeps a stale switch that
+    /// finishes late from silently overwriting a newer one on disk.
+    pub fn next_switch_token(&self) -> u64 {
+        self.switch_generation.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    /// Like `select_account`, but only persists if `token` is still the most
+    /// recent one handed out by `next_switch_token`. Returns `Ok(false)`
+    /// without touching the store when `token` has been superseded, so a
+    /// switch that's been cancelled (or replaced by a newer one) becomes a
+    /// no-op instead of clobbering whatever the newer switch already wrote.
+    pub fn select_account_if_current(&self, id: &str, token: u64) -> Result<bool> {
+        let mut data = self.data.lock().unwrap();
+        if !data.accounts.iter().any(|record| record.id == id) {
+            return Err(AuthError(format!("no such account: {id}")));
+        }
+        if self.switch_generation.load(Ordering::SeqCst) != token {
+            return Ok(false);
+        }
+        data.active_account_id = Some(id.to_string());
+        self.persist(&data)?;
+        Ok(true)
+    }
+
+    /// Removes the account with the given id. If it was the active account,
+    /// the active account falls back to whichever one is now first in the
+    /// list (or stays unset if that was the last account), leaving the
+    /// "flip to the login form when empty" decision to the caller.
+    pub fn remove_account(&self, id: &str) -> Result<()> {
+        let mut data = self.data.lock().unwrap();
+        let before = data.accounts.len();
+        data.accounts.retain(|record| record.id != id);
+        if data.accounts.len() == before {
+            return Err(AuthError(format!("no such account: {id}")));
+        }
+        if data.active_account_id.as_deref() == Some(id) {
+            data.active_account_id = data.accounts.first().map(|record| record.id.clone());
+        }
+        self.persist(&data)
+    }
+
+    /// Sets the display label a user has chosen for an account, persisted
+    /// alongside its credentials so it survives restarts and round-trips
+    /// through `export_accounts`/`import_accounts`.
+    pub fn set_account_label(&self, id: &str, label: String) -> Result<()> {
+        let mut data = self.data.lock().unwrap();
+        let record = data
+            .accounts
+            .iter_mut()
+            .find(|record| record.id == id)
+            .ok_or_else(|| AuthError(format!("no such account: {id}")))?;
+        record.custom_label = Some(label);
+        self.persist(&data)
+    }
+
+    /// Writes every stored account, including its secret, to `path` as a
+    /// single JSON document so it can be copied onto another machine.
+    pub fn export_accounts(&self, path: &Path) -> Result<()> {
+        let data = self.data.lock().unwrap();
+        let serialized = serde_json::to_string_pretty(&*data)?;
+        fs::write(path, serialized)?;
+        Ok(())
+    }
+
+    /// Reads a JSON document previously written by `export_accounts` and
+    /// merges its accounts into the store, skipping any id that's already
+    /// present rather than overwriting it.
+    pub fn import_accounts(&self, path: &Path) -> Result<()> {
+        let contents = fs::read_to_string(path)?;
+        let imported: AccountsData = serde_json::from_str(&contents)?;
+        let mut data = self.data.lock().unwrap();
+        for record in imported.accounts {
+            if !data
+                .accounts
+                .iter()
+                .any(|existing| existing.id == record.id)
+            {
+                data.accounts.push(record);
+            }
+        }
+        if data.active_account_id.is_none() {
+            data.active_account_id = imported.active_account_id;
+        }
+        self.persist(&data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    fn temp_codex_home(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("codex-auth-test-{name}-{nanos}"));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn record(id: &str) -> AccountRecord {
+        AccountRecord {
+            id: id.to_string(),
+            kind: AccountKind::ApiKey,
+            label: id.to_string(),
+            custom_label: None,
+            email: None,
+            api_key: Some(format!("sk-{id}-secret")),
+            chatgpt_credentials: None,
+        }
+    }
+
+    fn seed(manager: &AuthManager, accounts: Vec<AccountRecord>, active_account_id: Option<&str>) {
+        let mut data = manager.data.lock().unwrap();
+        data.accounts = accounts;
+        data.active_account_id = active_account_id.map(str::to_string);
+        manager.persist(&data).unwrap();
+    }
+
+    #[test]
+    fn select_account_if_current_noops_once_superseded() {
+        let manager = AuthManager::new(temp_codex_home("switch-race")).unwrap();
+        seed(&manager, vec![record("a")], None);
+
+        // Simulate: a switch starts (token 1), then gets superseded by a
+        // second switch (token 2) before the first one's blocking task
+        // reaches its persist check.
+        let stale_token = manager.next_switch_token();
+        manager.next_switch_token();
+
+        let applied = manager
+            .select_account_if_current("a", stale_token)
+            .unwrap();
+
+        assert!(!applied);
+        assert!(!manager.list_accounts().unwrap()[0].is_active);
+    }
+
+    #[test]
+    fn select_account_if_current_applies_when_token_is_current() {
+        let manager = AuthManager::new(temp_codex_home("switch-current")).unwrap();
+        seed(&manager, vec![record("a")], None);
+
+        let token = manager.next_switch_token();
+        let applied = manager.select_account_if_current("a", token).unwrap();
+
+        assert!(applied);
+        assert!(manager.list_accounts().unwrap()[0].is_active);
+    }
+
+    #[test]
+    fn remove_account_falls_back_to_first_remaining() {
+        let manager = AuthManager::new(temp_codex_home("remove")).unwrap();
+        seed(&manager, vec![record("a"), record("b")], Some("a"));
+
+        manager.remove_account("a").unwrap();
+
+        let accounts = manager.list_accounts().unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].id, "b");
+        assert!(accounts[0].is_active);
+    }
+
+    #[test]
+    fn remove_account_errors_on_unknown_id() {
+        let manager = AuthManager::new(temp_codex_home("remove-unknown")).unwrap();
+        assert!(manager.remove_account("missing").is_err());
+    }
+
+    #[test]
+    fn set_account_label_persists_custom_label() {
+        let manager = AuthManager::new(temp_codex_home("rename")).unwrap();
+        seed(&manager, vec![record("a")], None);
+
+        manager
+            .set_account_label("a", "Work".to_string())
+            .unwrap();
+
+        let accounts = manager.list_accounts().unwrap();
+        assert_eq!(accounts[0].custom_label.as_deref(), Some("Work"));
+    }
+
+    #[test]
+    fn set_account_label_errors_on_unknown_id() {
+        let manager = AuthManager::new(temp_codex_home("rename-unknown")).unwrap();
+        assert!(
+            manager
+                .set_account_label("missing", "Work".to_string())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn export_then_import_round_trips_accounts() {
+        let export_manager = AuthManager::new(temp_codex_home("export")).unwrap();
+        seed(&export_manager, vec![record("a"), record("b")], Some("a"));
+        let bundle_path = temp_codex_home("export-bundle").join("bundle.json");
+        export_manager.export_accounts(&bundle_path).unwrap();
+
+        let import_manager = AuthManager::new(temp_codex_home("import")).unwrap();
+        import_manager.import_accounts(&bundle_path).unwrap();
+
+        let accounts = import_manager.list_accounts().unwrap();
+        assert_eq!(accounts.len(), 2);
+        assert!(accounts.iter().any(|a| a.id == "a" && a.is_active));
+    }
+
+    #[test]
+    fn import_skips_accounts_that_already_exist() {
+        let export_manager = AuthManager::new(temp_codex_home("export-dup")).unwrap();
+        seed(&export_manager, vec![record("a")], None);
+        let bundle_path = temp_codex_home("export-dup-bundle").join("bundle.json");
+        export_manager.export_accounts(&bundle_path).unwrap();
+
+        let import_manager = AuthManager::new(temp_codex_home("import-dup")).unwrap();
+        seed(
+            &import_manager,
+            vec![AccountRecord {
+                label: "already here".to_string(),
+                ..record("a")
+            }],
+            None,
+        );
+
+        import_manager.import_accounts(&bundle_path).unwrap();
+
+        let accounts = import_manager.list_accounts().unwrap();
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(accounts[0].label, "already here");
+    }
+}